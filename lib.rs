@@ -17,6 +17,9 @@ mod fat_sample {
     };
     use scale::{Decode, Encode};
 
+    /// The version of the contract logic. Bump it on each release.
+    const VERSION: u32 = 1;
+
     #[ink(storage)]
     #[derive(SpreadAllocate)]
     pub struct FatSample {
@@ -130,6 +133,12 @@ mod fat_sample {
             self.poap_code.get(idx as usize).cloned()
         }
 
+        /// Returns the version of the contract logic. (View function)
+        #[ink(message)]
+        pub fn version(&self) -> u32 {
+            VERSION
+        }
+
         #[ink(message)]
         pub fn query_example(&self) -> (u16, Vec<u8>) {
             let resposne = http_get!("https://example.com");
@@ -315,6 +324,22 @@ mod fat_sample {
             );
         }
 
+        #[ink::test]
+        fn can_query_version() {
+            use pink_extension::chain_extension::mock;
+            // Mock derive key call (a pregenerated key pair)
+            mock::mock_derive_sr25519_key(|_| {
+                hex::decode("78003ee90ff2544789399de83c60fa50b3b24ca86c7512d0680f64119207c80ab240b41344968b3e3a71a02c0e8b454658e00e9310f443935ecadbdd1674c683").unwrap()
+            });
+            mock::mock_get_public_key(|_| {
+                hex::decode("ce786c340288b79a951c68f87da821d6c69abd1899dff695bda95e03f9c0b012")
+                    .unwrap()
+            });
+
+            let contract = FatSample::default();
+            assert_eq!(contract.version(), VERSION);
+        }
+
         #[ink::test]
         fn admin_access_control() {
             use pink_extension::chain_extension::mock;